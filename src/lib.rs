@@ -1,20 +1,37 @@
+use arrow::array::{
+    ArrayRef, FixedSizeListArray, Float32Array, Float64Array, Int16Array, Int32Array, Int8Array,
+    UInt16Array, UInt32Array, UInt8Array,
+};
+use arrow::buffer::ScalarBuffer;
+use arrow::compute::concat_batches;
+use arrow::datatypes::{DataType, Field as ArrowField, Schema as ArrowSchema};
+use arrow::pyarrow::PyArrowType;
+use arrow::record_batch::RecordBatch;
 use numpy::{
     IntoPyArray, PyArray1, PyArrayDescrMethods, PyArrayMethods, PyUntypedArray,
     PyUntypedArrayMethods,
 };
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, Encoding};
+use parquet::file::properties::WriterProperties;
+use parquet::schema::types::ColumnPath;
 use rs_pcd::decoder::ascii::AsciiReader;
 use rs_pcd::decoder::binary_par::BinaryParallelDecoder;
 use rs_pcd::decoder::compressed::CompressedReader;
 
 use pyo3::exceptions::{PyRuntimeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
-use rs_pcd::header::{DataFormat, PcdHeader, ValueType, parse_header};
+use pyo3::types::{PyBytes, PyDict, PyModule};
+use rs_pcd::header::{parse_header, DataFormat, PcdHeader, ValueType};
 use rs_pcd::io::{PcdReader, PcdWriter};
 use rs_pcd::layout::PcdLayout;
 use rs_pcd::storage::{Column, PointBlock};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufWriter, Cursor};
+use std::mem;
+use std::sync::Arc;
 
 /// Python-accessible metadata from PCD header
 #[pyclass]
@@ -34,26 +51,342 @@ pub struct MetaData {
     pub fields: Vec<String>,
 }
 
-/// Convert a Column reference to a PyObject (numpy array)
-fn column_to_pyarray(py: Python<'_>, column: &Column) -> PyObject {
+/// Iterates over a PCD file in fixed-size chunks without materializing the
+/// whole point cloud at once.
+///
+/// Obtained via `open_pcd_chunks(path, chunk_size)`. Backed by a
+/// memory-mapped reader, so each `__next__` call only decodes the next
+/// `chunk_size` points into a fresh dict of numpy arrays.
+///
+/// Deliberately yields just that dict rather than a (MetaData, dict) pair:
+/// the metadata is constant across every chunk of a given reader, so it's
+/// exposed once via the `metadata` getter instead of being rebuilt and
+/// handed back on every iteration.
+#[pyclass]
+struct PcdChunkReader {
+    reader: PcdReader,
+    columns: Option<Vec<String>>,
+    chunk_size: usize,
+    next_point: usize,
+}
+
+#[pymethods]
+impl PcdChunkReader {
+    #[getter]
+    fn metadata(&self) -> MetaData {
+        let header = self.reader.header();
+        MetaData {
+            version: header.version.clone(),
+            width: header.width,
+            height: header.height,
+            points: header.points,
+            viewpoint: header.viewpoint.to_vec(),
+            fields: header.fields.clone(),
+        }
+    }
+
+    /// Total number of points in the underlying PCD file.
+    #[getter]
+    fn total_points(&self) -> usize {
+        self.reader.header().points
+    }
+
+    /// Number of points not yet yielded by `__next__`.
+    fn remaining(&self) -> usize {
+        self.reader.header().points - self.next_point
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyDict>>> {
+        let total_points = slf.reader.header().points;
+        if slf.next_point >= total_points {
+            return Ok(None);
+        }
+
+        let len = slf.chunk_size.min(total_points - slf.next_point);
+        let start = slf.next_point;
+        let columns = slf.columns.clone();
+        let block = match &columns {
+            Some(names) => slf.reader.read_range_selected(start, len, names),
+            None => slf.reader.read_range(start, len),
+        }
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        slf.next_point += len;
+
+        let header = slf.reader.header();
+        let dict = PyDict::new(py);
+        for (idx, name) in block.schema().iter().enumerate() {
+            if let Some(column) = block.get_column_by_index(idx) {
+                let py_array = column_to_pyarray(py, column, field_count(header, name))?;
+                dict.set_item(name, py_array)?;
+            }
+        }
+        Ok(Some(dict.into()))
+    }
+}
+
+/// Open a PCD file for chunked, out-of-core reading.
+///
+/// Args:
+///     path: Input file path
+///     chunk_size: Number of points to decode per iteration
+///     columns: Optional subset of field names to materialize per chunk
+/// Returns a `PcdChunkReader` you can iterate over.
+#[pyfunction]
+#[pyo3(signature = (path, chunk_size, columns=None))]
+fn open_pcd_chunks(
+    path: String,
+    chunk_size: usize,
+    columns: Option<Vec<String>>,
+) -> PyResult<PcdChunkReader> {
+    if chunk_size == 0 {
+        return Err(PyTypeError::new_err("chunk_size must be greater than zero"));
+    }
+    let reader =
+        PcdReader::from_path_mmap(&path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    Ok(PcdChunkReader {
+        reader,
+        columns,
+        chunk_size,
+        next_point: 0,
+    })
+}
+
+/// Convert a Column to an Arrow array by taking its backing Vec<T> out of
+/// `column` (via `mem::take`) and wrapping it directly in an Arrow buffer, so
+/// the point data is moved into the Arrow array rather than duplicated.
+/// COUNT>1 fields (vector/descriptor columns) come back as a FixedSizeList.
+fn column_to_arrow_array(column: &mut Column, count: usize) -> ArrayRef {
+    let (flat, child_type): (ArrayRef, DataType) = match column {
+        Column::F32(v) => (
+            Arc::new(Float32Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::Float32,
+        ),
+        Column::F64(v) => (
+            Arc::new(Float64Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::Float64,
+        ),
+        Column::U8(v) => (
+            Arc::new(UInt8Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::UInt8,
+        ),
+        Column::U16(v) => (
+            Arc::new(UInt16Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::UInt16,
+        ),
+        Column::U32(v) => (
+            Arc::new(UInt32Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::UInt32,
+        ),
+        Column::I8(v) => (
+            Arc::new(Int8Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::Int8,
+        ),
+        Column::I16(v) => (
+            Arc::new(Int16Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::Int16,
+        ),
+        Column::I32(v) => (
+            Arc::new(Int32Array::new(ScalarBuffer::from(mem::take(v)), None)),
+            DataType::Int32,
+        ),
+    };
+
+    if count > 1 {
+        let item_field = Arc::new(ArrowField::new("item", child_type, false));
+        Arc::new(
+            FixedSizeListArray::try_new(item_field, count as i32, flat, None)
+                .expect("column length must be a multiple of its COUNT"),
+        )
+    } else {
+        flat
+    }
+}
+
+fn column_arrow_type(column: &Column, count: usize) -> DataType {
+    let child_type = match column {
+        Column::F32(_) => DataType::Float32,
+        Column::F64(_) => DataType::Float64,
+        Column::U8(_) => DataType::UInt8,
+        Column::U16(_) => DataType::UInt16,
+        Column::U32(_) => DataType::UInt32,
+        Column::I8(_) => DataType::Int8,
+        Column::I16(_) => DataType::Int16,
+        Column::I32(_) => DataType::Int32,
+    };
+
+    if count > 1 {
+        let item_field = Arc::new(ArrowField::new("item", child_type, false));
+        DataType::FixedSizeList(item_field, count as i32)
+    } else {
+        child_type
+    }
+}
+
+/// Look up a field's COUNT (number of values per point) from the header.
+/// Fields not present in the header (shouldn't normally happen) default to 1.
+fn field_count(header: &PcdHeader, name: &str) -> usize {
+    header
+        .fields
+        .iter()
+        .position(|f| f == name)
+        .map(|idx| header.counts[idx])
+        .unwrap_or(1)
+}
+
+/// Convert a Column reference to a PyObject (numpy array). COUNT>1 fields
+/// (vector/descriptor columns) come back as a 2D array of shape (points, count).
+fn column_to_pyarray(py: Python<'_>, column: &Column, count: usize) -> PyResult<PyObject> {
+    fn reshape<T: numpy::Element>(
+        py: Python<'_>,
+        values: Vec<T>,
+        count: usize,
+    ) -> PyResult<PyObject> {
+        let array = values.into_pyarray(py);
+        if count > 1 {
+            let rows = array.len() / count;
+            Ok(array.reshape([rows, count])?.into_any().unbind())
+        } else {
+            Ok(array.into_any().unbind())
+        }
+    }
+
+    match column {
+        Column::F32(v) => reshape(py, v.clone(), count),
+        Column::F64(v) => reshape(py, v.clone(), count),
+        Column::U8(v) => reshape(py, v.clone(), count),
+        Column::U16(v) => reshape(py, v.clone(), count),
+        Column::U32(v) => reshape(py, v.clone(), count),
+        Column::I8(v) => reshape(py, v.clone(), count),
+        Column::I16(v) => reshape(py, v.clone(), count),
+        Column::I32(v) => reshape(py, v.clone(), count),
+    }
+}
+
+/// Flatten a 1D or 2D (points, count) numpy array into the row-major `Vec<T>`
+/// that `Column` stores internally. Branches on the array's actual
+/// dimensionality rather than `count`, since a scalar field (COUNT=1) may
+/// still be passed as a 2D array of shape (N, 1) (e.g. `intensity.reshape(-1,
+/// 1)`).
+fn flatten_field<T: numpy::Element + Clone>(
+    array: &Bound<'_, PyUntypedArray>,
+    _count: usize,
+) -> PyResult<Vec<T>> {
+    match array.ndim() {
+        1 => {
+            let arr: &Bound<'_, PyArray1<T>> = array.downcast().map_err(|_| {
+                PyTypeError::new_err("Expected a 1D array matching the field's dtype")
+            })?;
+            arr.to_vec()
+        }
+        2 => {
+            let arr: &Bound<'_, numpy::PyArray2<T>> = array.downcast().map_err(|_| {
+                PyTypeError::new_err("Expected a 2D array matching the field's dtype")
+            })?;
+            Ok(arr.readonly().as_array().iter().cloned().collect())
+        }
+        _ => Err(PyTypeError::new_err(
+            "Field arrays must be 1D or 2D (points, count)",
+        )),
+    }
+}
+
+/// numpy typestr for a Column's element type (used to build structured dtypes)
+fn column_numpy_typestr(column: &Column) -> &'static str {
     match column {
-        Column::F32(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::F64(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::U8(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::U16(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::U32(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::I8(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::I16(v) => v.clone().into_pyarray(py).into_any().unbind(),
-        Column::I32(v) => v.clone().into_pyarray(py).into_any().unbind(),
+        Column::F32(_) => "f4",
+        Column::F64(_) => "f8",
+        Column::U8(_) => "u1",
+        Column::U16(_) => "u2",
+        Column::U32(_) => "u4",
+        Column::I8(_) => "i1",
+        Column::I16(_) => "i2",
+        Column::I32(_) => "i4",
     }
 }
 
+/// Build a single interleaved (structured-dtype) numpy array instead of a
+/// dict of per-field arrays, mirroring the point-major layout of PCD's
+/// binary encoding.
+fn block_to_structured_array(
+    py: Python<'_>,
+    header: &PcdHeader,
+    block: &PointBlock,
+) -> PyResult<PyObject> {
+    let numpy = PyModule::import(py, "numpy")?;
+
+    let mut dtype_spec = Vec::new();
+    let mut columns = Vec::new();
+    for (idx, name) in block.schema().iter().enumerate() {
+        if let Some(column) = block.get_column_by_index(idx) {
+            let count = field_count(header, name);
+            let typestr = column_numpy_typestr(column);
+            if count > 1 {
+                dtype_spec.push((name.clone(), typestr, count).into_py(py));
+            } else {
+                dtype_spec.push((name.clone(), typestr).into_py(py));
+            }
+            columns.push((name, column, count));
+        }
+    }
+
+    let dtype = numpy.call_method1("dtype", (dtype_spec,))?;
+    let out = numpy.call_method1("empty", (header.points, dtype))?;
+    for (name, column, count) in columns {
+        let py_array = column_to_pyarray(py, column, count)?;
+        out.set_item(name, py_array)?;
+    }
+
+    Ok(out.into())
+}
+
+/// Convert a decoded PointBlock into either a dict of per-field numpy arrays
+/// or, when `as_struct` is set, a single interleaved structured array.
+fn block_to_pyobject(
+    py: Python<'_>,
+    header: &PcdHeader,
+    block: &PointBlock,
+    as_struct: bool,
+) -> PyResult<PyObject> {
+    if as_struct {
+        return block_to_structured_array(py, header, block);
+    }
+
+    let dict = PyDict::new(py);
+    // Use schema() and get_column_by_index() for iteration (v0.2.0 API)
+    for (idx, name) in block.schema().iter().enumerate() {
+        if let Some(column) = block.get_column_by_index(idx) {
+            let py_array = column_to_pyarray(py, column, field_count(header, name))?;
+            dict.set_item(name, py_array)?;
+        }
+    }
+    Ok(dict.into())
+}
+
 /// Read a PCD file from disk.
-/// 
+///
 /// Uses memory-mapped I/O for maximum performance.
-/// Returns (metadata, dict of numpy arrays).
+///
+/// Args:
+///     path: Input file path
+///     columns: Optional subset of field names to materialize. Fields left
+///         out are skipped during decoding rather than read and discarded,
+///         so this also cuts I/O and allocation cost for wide point clouds.
+///     as_struct: If True, return a single interleaved structured numpy
+///         array (one record per point) instead of a dict of per-field
+///         arrays.
+/// Returns (metadata, dict of numpy arrays, or a structured array if
+/// as_struct=True).
 #[pyfunction]
-fn read_pcd(path: String) -> PyResult<(MetaData, Py<PyDict>)> {
+#[pyo3(signature = (path, columns=None, as_struct=false))]
+fn read_pcd(
+    path: String,
+    columns: Option<Vec<String>>,
+    as_struct: bool,
+) -> PyResult<(MetaData, PyObject)> {
     let reader =
         PcdReader::from_path_mmap(&path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
     let header = reader.header();
@@ -67,31 +400,41 @@ fn read_pcd(path: String) -> PyResult<(MetaData, Py<PyDict>)> {
         fields: header.fields.clone(),
     };
 
-    let block = reader
-        .read_all()
-        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let block = match &columns {
+        Some(names) => reader
+            .read_selected(names)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+        None => reader
+            .read_all()
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+    };
 
     Python::with_gil(|py| {
-        let dict = PyDict::new(py);
-
-        // Use schema() and get_column_by_index() for iteration (v0.2.0 API)
-        for (idx, name) in block.schema().iter().enumerate() {
-            if let Some(column) = block.get_column_by_index(idx) {
-                let py_array = column_to_pyarray(py, column);
-                dict.set_item(name, py_array)?;
-            }
-        }
-
-        Ok((meta, dict.into()))
+        let result = block_to_pyobject(py, header, &block, as_struct)?;
+        Ok((meta, result))
     })
 }
 
 /// Read a PCD file from a bytes buffer.
-/// 
+///
 /// Useful for reading from network streams or embedded resources.
-/// Returns (metadata, dict of numpy arrays).
+///
+/// Args:
+///     buffer: Raw PCD bytes
+///     columns: Optional subset of field names to materialize. Fields left
+///         out are skipped during decoding rather than read and discarded.
+///     as_struct: If True, return a single interleaved structured numpy
+///         array (one record per point) instead of a dict of per-field
+///         arrays.
+/// Returns (metadata, dict of numpy arrays, or a structured array if
+/// as_struct=True).
 #[pyfunction]
-fn read_pcd_from_buffer(buffer: &Bound<'_, PyBytes>) -> PyResult<(MetaData, Py<PyDict>)> {
+#[pyo3(signature = (buffer, columns=None, as_struct=false))]
+fn read_pcd_from_buffer(
+    buffer: &Bound<'_, PyBytes>,
+    columns: Option<Vec<String>>,
+    as_struct: bool,
+) -> PyResult<(MetaData, PyObject)> {
     let data = buffer.as_bytes();
     let mut cursor = Cursor::new(data);
 
@@ -104,8 +447,28 @@ fn read_pcd_from_buffer(buffer: &Bound<'_, PyBytes>) -> PyResult<(MetaData, Py<P
     let schema: Vec<(String, ValueType)> = layout
         .fields
         .iter()
+        .filter(|f| {
+            columns
+                .as_ref()
+                .is_none_or(|cols| cols.iter().any(|c| c == &f.name))
+        })
         .map(|f| (f.name.clone(), f.type_))
         .collect();
+
+    if let Some(cols) = &columns {
+        for name in cols {
+            if !schema.iter().any(|(n, _)| n == name) {
+                return Err(PyRuntimeError::new_err(format!(
+                    "Unknown field '{}' requested",
+                    name
+                )));
+            }
+        }
+    }
+
+    // `layout` still describes every field's byte offsets so the decoders
+    // can walk the record correctly; `block` only allocates columns for the
+    // requested subset, so unrequested fields are skipped rather than copied.
     let mut block = PointBlock::new(&schema, points);
 
     let data_slice = &data[start_pos..];
@@ -143,44 +506,221 @@ fn read_pcd_from_buffer(buffer: &Bound<'_, PyBytes>) -> PyResult<(MetaData, Py<P
     };
 
     Python::with_gil(|py| {
-        let dict = PyDict::new(py);
-        
-        // Use schema() and get_column_by_index() for iteration (v0.2.0 API)
-        for (idx, name) in block.schema().iter().enumerate() {
-            if let Some(column) = block.get_column_by_index(idx) {
-                let py_array = column_to_pyarray(py, column);
-                dict.set_item(name, py_array)?;
+        let result = block_to_pyobject(py, &header, &block, as_struct)?;
+        Ok((meta, result))
+    })
+}
+
+/// Convert a decoded PointBlock into an Arrow RecordBatch, consuming each
+/// column's backing Vec<T> in place (see `column_to_arrow_array`) so no
+/// column data is duplicated between the PCD decode and the Arrow export.
+/// Shared by `pcd_path_to_record_batch` and `read_pcd_arrow_from_buffer`.
+fn block_to_record_batch(header: &PcdHeader, block: &mut PointBlock) -> PyResult<RecordBatch> {
+    let field_names: Vec<String> = block.schema().to_vec();
+    let mut arrow_fields = Vec::new();
+    let mut arrays: Vec<ArrayRef> = Vec::new();
+    for name in &field_names {
+        if let Some(column) = block.get_column_mut(name) {
+            let count = field_count(header, name);
+            arrow_fields.push(ArrowField::new(
+                name,
+                column_arrow_type(column, count),
+                false,
+            ));
+            arrays.push(column_to_arrow_array(column, count));
+        }
+    }
+
+    let schema = Arc::new(ArrowSchema::new(arrow_fields));
+    RecordBatch::try_new(schema, arrays).map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+/// Decode a PCD file from disk into an Arrow RecordBatch plus its metadata.
+/// Used by `read_pcd_arrow`; goes through the mmap reader and then the same
+/// column conversion as `read_pcd_arrow_from_buffer`.
+fn pcd_path_to_record_batch(
+    path: &str,
+    columns: Option<&[String]>,
+) -> PyResult<(MetaData, RecordBatch)> {
+    let reader =
+        PcdReader::from_path_mmap(path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let header = reader.header();
+
+    let meta = MetaData {
+        version: header.version.clone(),
+        width: header.width,
+        height: header.height,
+        points: header.points,
+        viewpoint: header.viewpoint.to_vec(),
+        fields: header.fields.clone(),
+    };
+
+    let mut block = match columns {
+        Some(names) => reader
+            .read_selected(names)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+        None => reader
+            .read_all()
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+    };
+
+    let batch = block_to_record_batch(header, &mut block)?;
+    Ok((meta, batch))
+}
+
+/// Read a PCD file from disk as a pyarrow Table.
+///
+/// Decoded columns are moved (not copied) into the Arrow arrays, which are
+/// then exported to pyarrow through the Arrow C Data Interface, so no extra
+/// copy happens on either side of the handoff.
+///
+/// Args:
+///     path: Input file path
+///     columns: Optional subset of field names to materialize.
+/// Returns (metadata, pyarrow.Table).
+#[pyfunction]
+#[pyo3(signature = (path, columns=None))]
+fn read_pcd_arrow(path: String, columns: Option<Vec<String>>) -> PyResult<(MetaData, PyObject)> {
+    let (meta, batch) = pcd_path_to_record_batch(&path, columns.as_deref())?;
+    Python::with_gil(|py| {
+        let batch = PyArrowType(batch).into_py(py);
+        let table = PyModule::import(py, "pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_batches", (vec![batch],))?;
+        Ok((meta, table.unbind()))
+    })
+}
+
+/// Read a PCD file from a bytes buffer as a pyarrow Table.
+///
+/// Arrow equivalent of `read_pcd_from_buffer`. Decoded columns are moved
+/// (not copied) into the Arrow arrays, which are then exported to pyarrow
+/// through the Arrow C Data Interface.
+///
+/// Args:
+///     buffer: Raw PCD bytes
+///     columns: Optional subset of field names to materialize. Fields left
+///         out are skipped during decoding rather than read and discarded.
+/// Returns (metadata, pyarrow.Table).
+#[pyfunction]
+#[pyo3(signature = (buffer, columns=None))]
+fn read_pcd_arrow_from_buffer(
+    buffer: &Bound<'_, PyBytes>,
+    columns: Option<Vec<String>>,
+) -> PyResult<(MetaData, PyObject)> {
+    let data = buffer.as_bytes();
+    let mut cursor = Cursor::new(data);
+
+    let header = parse_header(&mut cursor).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let layout =
+        PcdLayout::from_header(&header).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let start_pos = cursor.position() as usize;
+
+    let points = header.points;
+    let schema: Vec<(String, ValueType)> = layout
+        .fields
+        .iter()
+        .filter(|f| {
+            columns
+                .as_ref()
+                .is_none_or(|cols| cols.iter().any(|c| c == &f.name))
+        })
+        .map(|f| (f.name.clone(), f.type_))
+        .collect();
+
+    if let Some(cols) = &columns {
+        for name in cols {
+            if !schema.iter().any(|(n, _)| n == name) {
+                return Err(PyRuntimeError::new_err(format!(
+                    "Unknown field '{}' requested",
+                    name
+                )));
             }
         }
-        
-        Ok((meta, dict.into()))
+    }
+
+    let mut block = PointBlock::new(&schema, points);
+    let data_slice = &data[start_pos..];
+
+    match header.data {
+        DataFormat::Binary => {
+            let decoder = BinaryParallelDecoder::new(&layout, points);
+            decoder
+                .decode_par(data_slice, &mut block)
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        }
+        DataFormat::BinaryCompressed => {
+            let mut cursor = Cursor::new(data_slice);
+            let mut decoder = CompressedReader::new(&mut cursor, &layout, points);
+            decoder
+                .decode(&mut block)
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        }
+        DataFormat::Ascii => {
+            let mut cursor = Cursor::new(data_slice);
+            let mut decoder = AsciiReader::new(&mut cursor, &layout, points);
+            decoder
+                .decode(&mut block)
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        }
+    }
+
+    let meta = MetaData {
+        version: header.version.clone(),
+        width: header.width,
+        height: header.height,
+        points: header.points,
+        viewpoint: header.viewpoint.to_vec(),
+        fields: header.fields.clone(),
+    };
+
+    let batch = block_to_record_batch(&header, &mut block)?;
+    Python::with_gil(|py| {
+        let batch = PyArrowType(batch).into_py(py);
+        let table = PyModule::import(py, "pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_batches", (vec![batch],))?;
+        Ok((meta, table.unbind()))
     })
 }
 
 /// Write a PCD file to disk.
-/// 
+///
 /// Args:
 ///     path: Output file path
 ///     data: Dict of field_name -> numpy array
 ///     format: "ascii", "binary", or "binary_compressed"
 ///     viewpoint: Optional [tx, ty, tz, qw, qx, qy, qz] (default: identity)
-#[pyfunction]
-#[pyo3(signature = (path, data, format="binary", viewpoint=None))]
-fn write_pcd(
-    path: String,
-    data: &Bound<'_, PyDict>,
-    format: &str,
-    viewpoint: Option<Vec<f64>>,
-) -> PyResult<()> {
-    let data_format = match format {
-        "ascii" => DataFormat::Ascii,
-        "binary" => DataFormat::Binary,
-        "binary_compressed" => DataFormat::BinaryCompressed,
-        _ => return Err(PyTypeError::new_err("Unsupported format. Use 'ascii', 'binary', or 'binary_compressed'")),
-    };
+/// Parse and validate an optional [tx, ty, tz, qw, qx, qy, qz] viewpoint,
+/// defaulting to identity. Shared by `write_pcd` and `write_parquet`.
+fn parse_viewpoint(viewpoint: Option<Vec<f64>>) -> PyResult<[f64; 7]> {
+    let mut vp = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+    if let Some(v) = viewpoint {
+        if v.len() != 7 {
+            return Err(PyRuntimeError::new_err(
+                "Viewpoint must have 7 elements: [tx, ty, tz, qw, qx, qy, qz]",
+            ));
+        }
+        vp.copy_from_slice(&v);
+    }
+    Ok(vp)
+}
 
+/// Extract (fields, counts, column_data, points) from a dict of field_name
+/// -> numpy array, shared by `write_pcd` and `write_parquet`. A field is
+/// either 1D (COUNT=1) or 2D, shape (points, count), for vector/descriptor
+/// columns such as normals or feature embeddings.
+fn dict_to_columns(
+    data: &Bound<'_, PyDict>,
+) -> PyResult<(
+    Vec<(String, ValueType)>,
+    Vec<usize>,
+    Vec<(String, Column)>,
+    usize,
+)> {
     let py = data.py();
     let mut fields: Vec<(String, ValueType)> = Vec::new();
+    let mut counts: Vec<usize> = Vec::new();
     let mut points = 0;
     let mut column_data: Vec<(String, Column)> = Vec::new();
 
@@ -190,12 +730,16 @@ fn write_pcd(
             PyTypeError::new_err(format!("Value for field '{}' must be a numpy array", name))
         })?;
 
-        if array.ndim() != 1 {
-            return Err(PyTypeError::new_err(format!(
-                "Field '{}' must be a 1D array",
-                name
-            )));
-        }
+        let count = match array.ndim() {
+            1 => 1,
+            2 => array.shape()[1],
+            _ => {
+                return Err(PyTypeError::new_err(format!(
+                    "Field '{}' must be a 1D or 2D array",
+                    name
+                )));
+            }
+        };
 
         let num_elements = array.shape()[0];
         if points == 0 {
@@ -208,29 +752,21 @@ fn write_pcd(
 
         let dtype = array.dtype();
         let (vtype, column) = if dtype.is_equiv_to(&numpy::dtype::<f32>(py)) {
-            let arr: &Bound<'_, PyArray1<f32>> = array.downcast().unwrap();
-            (ValueType::F32, Column::F32(arr.to_vec()?))
+            (ValueType::F32, Column::F32(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<f64>(py)) {
-            let arr: &Bound<'_, PyArray1<f64>> = array.downcast().unwrap();
-            (ValueType::F64, Column::F64(arr.to_vec()?))
+            (ValueType::F64, Column::F64(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<u8>(py)) {
-            let arr: &Bound<'_, PyArray1<u8>> = array.downcast().unwrap();
-            (ValueType::U8, Column::U8(arr.to_vec()?))
+            (ValueType::U8, Column::U8(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<u16>(py)) {
-            let arr: &Bound<'_, PyArray1<u16>> = array.downcast().unwrap();
-            (ValueType::U16, Column::U16(arr.to_vec()?))
+            (ValueType::U16, Column::U16(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<u32>(py)) {
-            let arr: &Bound<'_, PyArray1<u32>> = array.downcast().unwrap();
-            (ValueType::U32, Column::U32(arr.to_vec()?))
+            (ValueType::U32, Column::U32(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<i8>(py)) {
-            let arr: &Bound<'_, PyArray1<i8>> = array.downcast().unwrap();
-            (ValueType::I8, Column::I8(arr.to_vec()?))
+            (ValueType::I8, Column::I8(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<i16>(py)) {
-            let arr: &Bound<'_, PyArray1<i16>> = array.downcast().unwrap();
-            (ValueType::I16, Column::I16(arr.to_vec()?))
+            (ValueType::I16, Column::I16(flatten_field(array, count)?))
         } else if dtype.is_equiv_to(&numpy::dtype::<i32>(py)) {
-            let arr: &Bound<'_, PyArray1<i32>> = array.downcast().unwrap();
-            (ValueType::I32, Column::I32(arr.to_vec()?))
+            (ValueType::I32, Column::I32(flatten_field(array, count)?))
         } else {
             return Err(PyTypeError::new_err(format!(
                 "Unsupported numpy dtype for field '{}'. Supported: f32, f64, u8, u16, u32, i8, i16, i32",
@@ -239,16 +775,34 @@ fn write_pcd(
         };
 
         fields.push((name.clone(), vtype));
+        counts.push(count);
         column_data.push((name, column));
     }
 
-    let mut vp = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
-    if let Some(v) = viewpoint {
-        if v.len() != 7 {
-            return Err(PyRuntimeError::new_err("Viewpoint must have 7 elements: [tx, ty, tz, qw, qx, qy, qz]"));
+    Ok((fields, counts, column_data, points))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, data, format="binary", viewpoint=None))]
+fn write_pcd(
+    path: String,
+    data: &Bound<'_, PyDict>,
+    format: &str,
+    viewpoint: Option<Vec<f64>>,
+) -> PyResult<()> {
+    let data_format = match format {
+        "ascii" => DataFormat::Ascii,
+        "binary" => DataFormat::Binary,
+        "binary_compressed" => DataFormat::BinaryCompressed,
+        _ => {
+            return Err(PyTypeError::new_err(
+                "Unsupported format. Use 'ascii', 'binary', or 'binary_compressed'",
+            ))
         }
-        vp.copy_from_slice(&v);
-    }
+    };
+
+    let (fields, counts, column_data, points) = dict_to_columns(data)?;
+    let vp = parse_viewpoint(viewpoint)?;
 
     let header = PcdHeader {
         version: "0.7".to_string(),
@@ -262,7 +816,7 @@ fn write_pcd(
                 ValueType::F32 | ValueType::F64 => 'F',
             })
             .collect(),
-        counts: vec![1; fields.len()],
+        counts,
         width: points as u32,
         height: 1,
         viewpoint: vp,
@@ -270,9 +824,10 @@ fn write_pcd(
         data: data_format,
     };
 
-    // Create PointBlock using the new API
-    let mut block = PointBlock::new(&fields, points);
-    
+    // Create PointBlock, sizing each column by its own COUNT (vector fields
+    // store `points * count` values back-to-back per point).
+    let mut block = PointBlock::with_counts(&fields, &header.counts, points);
+
     // Copy data into block columns
     for (name, src_column) in column_data {
         if let Some(dest_column) = block.get_column_mut(&name) {
@@ -300,17 +855,515 @@ fn write_pcd(
     Ok(())
 }
 
+/// Parse and validate an optional [tx, ty, tz, qw, qx, qy, qz] viewpoint,
+/// defaulting to identity. Shared by `write_pcd` and `write_parquet`.
+fn parse_viewpoint(viewpoint: Option<Vec<f64>>) -> PyResult<[f64; 7]> {
+    let mut vp = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+    if let Some(v) = viewpoint {
+        if v.len() != 7 {
+            return Err(PyRuntimeError::new_err(
+                "Viewpoint must have 7 elements: [tx, ty, tz, qw, qx, qy, qz]",
+            ));
+        }
+        vp.copy_from_slice(&v);
+    }
+    Ok(vp)
+}
+
+/// Extract (fields, counts, column_data, points) from a dict of field_name
+/// -> numpy array, shared by `write_pcd` and `write_parquet`. A field is
+/// either 1D (COUNT=1) or 2D, shape (points, count), for vector/descriptor
+/// columns such as normals or feature embeddings.
+fn dict_to_columns(
+    data: &Bound<'_, PyDict>,
+) -> PyResult<(
+    Vec<(String, ValueType)>,
+    Vec<usize>,
+    Vec<(String, Column)>,
+    usize,
+)> {
+    let py = data.py();
+    let mut fields: Vec<(String, ValueType)> = Vec::new();
+    let mut counts: Vec<usize> = Vec::new();
+    let mut points = 0;
+    let mut column_data: Vec<(String, Column)> = Vec::new();
+
+    for (key, value) in data.iter() {
+        let name: String = key.extract()?;
+        let array = value.downcast::<PyUntypedArray>().map_err(|_| {
+            PyTypeError::new_err(format!("Value for field '{}' must be a numpy array", name))
+        })?;
+
+        let count = match array.ndim() {
+            1 => 1,
+            2 => array.shape()[1],
+            _ => {
+                return Err(PyTypeError::new_err(format!(
+                    "Field '{}' must be a 1D or 2D array",
+                    name
+                )));
+            }
+        };
+
+        let num_elements = array.shape()[0];
+        if points == 0 {
+            points = num_elements;
+        } else if points != num_elements {
+            return Err(PyRuntimeError::new_err(
+                "All arrays must have the same length",
+            ));
+        }
+
+        let dtype = array.dtype();
+        let (vtype, column) = if dtype.is_equiv_to(&numpy::dtype::<f32>(py)) {
+            (ValueType::F32, Column::F32(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<f64>(py)) {
+            (ValueType::F64, Column::F64(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<u8>(py)) {
+            (ValueType::U8, Column::U8(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<u16>(py)) {
+            (ValueType::U16, Column::U16(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<u32>(py)) {
+            (ValueType::U32, Column::U32(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<i8>(py)) {
+            (ValueType::I8, Column::I8(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<i16>(py)) {
+            (ValueType::I16, Column::I16(flatten_field(array, count)?))
+        } else if dtype.is_equiv_to(&numpy::dtype::<i32>(py)) {
+            (ValueType::I32, Column::I32(flatten_field(array, count)?))
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "Unsupported numpy dtype for field '{}'. Supported: f32, f64, u8, u16, u32, i8, i16, i32",
+                name
+            )));
+        };
+
+        fields.push((name.clone(), vtype));
+        counts.push(count);
+        column_data.push((name, column));
+    }
+
+    Ok((fields, counts, column_data, points))
+}
+
+fn parquet_compression(name: &str) -> PyResult<Compression> {
+    match name {
+        "snappy" => Ok(Compression::SNAPPY),
+        "gzip" => Ok(Compression::GZIP(Default::default())),
+        "zstd" => Ok(Compression::ZSTD(Default::default())),
+        "none" | "uncompressed" => Ok(Compression::UNCOMPRESSED),
+        _ => Err(PyTypeError::new_err(format!(
+            "Unsupported compression '{}'. Use 'snappy', 'gzip', 'zstd', or 'none'",
+            name
+        ))),
+    }
+}
+
+/// Geometry fields are high-entropy floats that rarely shrink under generic
+/// compression, so they're stored PLAIN and uncompressed; everything else
+/// gets the requested codec.
+fn is_geometry_field(name: &str) -> bool {
+    matches!(name, "x" | "y" | "z")
+}
+
+/// 8/16-bit integer fields (labels, classifications, ring indices, ...) are
+/// typically low-cardinality and benefit from dictionary encoding; wider
+/// integer fields (e.g. point IDs) are left alone since they often aren't.
+fn is_low_cardinality_integer(vtype: ValueType) -> bool {
+    matches!(
+        vtype,
+        ValueType::U8 | ValueType::U16 | ValueType::I8 | ValueType::I16
+    )
+}
+
+/// A COUNT=1 field is a leaf column named directly after the field, but a
+/// COUNT>1 field is written as a `FixedSizeList`, whose parquet leaf lives
+/// nested under it (`<name>.list.item`, mirroring the arrow-to-parquet
+/// schema conversion for list types) — the per-column overrides below need
+/// the right leaf path or they silently no-op.
+fn parquet_leaf_path(name: &str, count: usize) -> ColumnPath {
+    if count > 1 {
+        ColumnPath::from(vec![
+            name.to_string(),
+            "list".to_string(),
+            "item".to_string(),
+        ])
+    } else {
+        ColumnPath::from(name)
+    }
+}
+
+/// Build per-column encoding/compression overrides on top of a blanket
+/// codec: PLAIN/uncompressed for geometry, dictionary encoding for
+/// low-cardinality integers, and the requested compression for the rest.
+fn parquet_writer_properties(
+    compression: Compression,
+    fields: &[(String, ValueType)],
+    counts: &[usize],
+) -> WriterProperties {
+    let mut builder = WriterProperties::builder().set_compression(compression);
+    for ((name, vtype), count) in fields.iter().zip(counts) {
+        let path = parquet_leaf_path(name, *count);
+        if is_geometry_field(name) {
+            builder = builder
+                .set_column_compression(path.clone(), Compression::UNCOMPRESSED)
+                .set_column_encoding(path.clone(), Encoding::PLAIN)
+                .set_column_dictionary_enabled(path, false);
+        } else if is_low_cardinality_integer(*vtype) {
+            builder = builder.set_column_dictionary_enabled(path, true);
+        }
+    }
+    builder.build()
+}
+
+/// Custom key/value metadata written into the parquet file's Arrow schema so
+/// `read_parquet` can recover PCD-level fields (width, height, viewpoint)
+/// that have no equivalent in a plain Arrow/parquet schema.
+fn pcd_metadata_map(width: u32, height: u32, viewpoint: &[f64; 7]) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert("pcd.version".to_string(), "0.7".to_string());
+    map.insert("pcd.width".to_string(), width.to_string());
+    map.insert("pcd.height".to_string(), height.to_string());
+    map.insert(
+        "pcd.viewpoint".to_string(),
+        viewpoint
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    map
+}
+
+/// Recover (width, height, viewpoint) from `pcd_metadata_map`, falling back
+/// to PCD defaults for parquet files that weren't written by `write_parquet`.
+fn pcd_metadata_from_schema(schema: &ArrowSchema, default_width: u32) -> (u32, u32, [f64; 7]) {
+    let metadata = schema.metadata();
+    let width = metadata
+        .get("pcd.width")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default_width);
+    let height = metadata
+        .get("pcd.height")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1);
+    let viewpoint = metadata
+        .get("pcd.viewpoint")
+        .and_then(|v| {
+            let parts: Vec<f64> = v.split(',').filter_map(|p| p.parse().ok()).collect();
+            if parts.len() == 7 {
+                let mut arr = [0.0; 7];
+                arr.copy_from_slice(&parts);
+                Some(arr)
+            } else {
+                None
+            }
+        })
+        .unwrap_or([0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    (width, height, viewpoint)
+}
+
+/// Convert an Arrow array (optionally a FixedSizeList for COUNT>1 fields)
+/// back into the (ValueType, count, Column) triple `write_pcd`/`write_parquet`
+/// work with.
+fn arrow_array_to_column(array: &ArrayRef) -> PyResult<(ValueType, usize, Column)> {
+    let (count, values) = match array.data_type() {
+        DataType::FixedSizeList(_, size) => (
+            *size as usize,
+            array
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .unwrap()
+                .values()
+                .clone(),
+        ),
+        _ => (1, array.clone()),
+    };
+
+    let err = || PyTypeError::new_err("Unsupported parquet column type for PCD export");
+    macro_rules! column_of {
+        ($arrow_ty:ty, $variant:ident, $value_type:expr) => {
+            values
+                .as_any()
+                .downcast_ref::<$arrow_ty>()
+                .map(|a| (($value_type), count, Column::$variant(a.values().to_vec())))
+        };
+    }
+
+    column_of!(Float32Array, F32, ValueType::F32)
+        .or_else(|| column_of!(Float64Array, F64, ValueType::F64))
+        .or_else(|| column_of!(UInt8Array, U8, ValueType::U8))
+        .or_else(|| column_of!(UInt16Array, U16, ValueType::U16))
+        .or_else(|| column_of!(UInt32Array, U32, ValueType::U32))
+        .or_else(|| column_of!(Int8Array, I8, ValueType::I8))
+        .or_else(|| column_of!(Int16Array, I16, ValueType::I16))
+        .or_else(|| column_of!(Int32Array, I32, ValueType::I32))
+        .ok_or_else(err)
+}
+
+/// Write a dict of field_name -> numpy array directly to Parquet (the same
+/// shape of `data` that `write_pcd` accepts), with no intermediate PCD file.
+///
+/// Columnar encoding is chosen per field: geometry fields (x, y, z) are
+/// stored PLAIN/uncompressed since floating-point positions rarely shrink
+/// under generic compression, low-cardinality 8/16-bit integer fields
+/// (labels, classifications, ring indices, ...) get dictionary encoding, and
+/// every other field uses the requested `compression` codec.
+///
+/// Args:
+///     path: Output parquet file path
+///     data: Dict of field_name -> numpy array (same as `write_pcd`)
+///     compression: "zstd" (default), "snappy", "gzip", or "none"
+///     viewpoint: Optional [tx, ty, tz, qw, qx, qy, qz] (default: identity),
+///         preserved in the file's schema metadata for `read_parquet`
+#[pyfunction]
+#[pyo3(signature = (path, data, compression="zstd", viewpoint=None))]
+fn write_parquet(
+    path: String,
+    data: &Bound<'_, PyDict>,
+    compression: &str,
+    viewpoint: Option<Vec<f64>>,
+) -> PyResult<()> {
+    let (fields, counts, column_data, points) = dict_to_columns(data)?;
+    let vp = parse_viewpoint(viewpoint)?;
+
+    // `column_data` isn't used after this, so each column's Vec<T> can be
+    // taken by value (see `column_to_arrow_array`) instead of cloned.
+    let mut arrow_fields = Vec::with_capacity(fields.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(fields.len());
+    for (((name, _), count), (_, mut column)) in fields.iter().zip(&counts).zip(column_data) {
+        arrow_fields.push(ArrowField::new(
+            name,
+            column_arrow_type(&column, *count),
+            false,
+        ));
+        arrays.push(column_to_arrow_array(&mut column, *count));
+    }
+
+    let schema = Arc::new(
+        ArrowSchema::new(arrow_fields).with_metadata(pcd_metadata_map(points as u32, 1, &vp)),
+    );
+    let batch = RecordBatch::try_new(schema.clone(), arrays)
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+    let props = parquet_writer_properties(parquet_compression(compression)?, &fields, &counts);
+
+    let file = File::create(&path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let mut writer = ArrowWriter::try_new(file, schema, Some(props))
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    writer
+        .write(&batch)
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    writer
+        .close()
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Read a Parquet file (as produced by `write_parquet`, or any columnar
+/// table with PCD-compatible dtypes) into the same (MetaData, dict) shape
+/// `read_pcd` returns, with no intermediate PCD file. Width, height and
+/// viewpoint are recovered from the file's schema metadata when present.
+///
+/// Args:
+///     path: Input parquet file path
+#[pyfunction]
+fn read_parquet(py: Python<'_>, path: String) -> PyResult<(MetaData, Py<PyDict>)> {
+    let file = File::open(&path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let reader_builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let schema = reader_builder.schema().clone();
+    let reader = reader_builder
+        .build()
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let batches = reader
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let batch =
+        concat_batches(&schema, &batches).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+    let points = batch.num_rows();
+    let (width, height, viewpoint) = pcd_metadata_from_schema(&schema, points as u32);
+    let field_names: Vec<String> = schema.fields().iter().map(|f| f.name().clone()).collect();
+
+    let dict = PyDict::new(py);
+    for (field, array) in schema.fields().iter().zip(batch.columns()) {
+        let (_, count, column) = arrow_array_to_column(array)?;
+        dict.set_item(field.name(), column_to_pyarray(py, &column, count)?)?;
+    }
+
+    let meta = MetaData {
+        version: "0.7".to_string(),
+        width,
+        height,
+        points,
+        viewpoint: viewpoint.to_vec(),
+        fields: field_names,
+    };
+
+    Ok((meta, dict.into()))
+}
+
 /// pcd-py: High-performance PCD I/O for Python
-/// 
+///
 /// Functions:
-///     read_pcd(path) -> (MetaData, dict)
-///     read_pcd_from_buffer(bytes) -> (MetaData, dict)
+///     read_pcd(path, columns=None, as_struct=False) -> (MetaData, dict | ndarray)
+///     read_pcd_from_buffer(bytes, columns=None, as_struct=False) -> (MetaData, dict | ndarray)
+///     read_pcd_arrow(path, columns=None) -> (MetaData, pyarrow.Table)
+///     read_pcd_arrow_from_buffer(bytes, columns=None) -> (MetaData, pyarrow.Table)
 ///     write_pcd(path, data, format="binary", viewpoint=None)
+///     open_pcd_chunks(path, chunk_size, columns=None) -> PcdChunkReader
+///     write_parquet(path, data, compression="zstd", viewpoint=None)
+///     read_parquet(path) -> (MetaData, dict)
 #[pymodule]
 fn pcd_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<MetaData>()?;
+    m.add_class::<PcdChunkReader>()?;
     m.add_function(wrap_pyfunction!(read_pcd, m)?)?;
     m.add_function(wrap_pyfunction!(read_pcd_from_buffer, m)?)?;
+    m.add_function(wrap_pyfunction!(read_pcd_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(read_pcd_arrow_from_buffer, m)?)?;
     m.add_function(wrap_pyfunction!(write_pcd, m)?)?;
+    m.add_function(wrap_pyfunction!(open_pcd_chunks, m)?)?;
+    m.add_function(wrap_pyfunction!(write_parquet, m)?)?;
+    m.add_function(wrap_pyfunction!(read_parquet, m)?)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numpy::IntoPyArray;
+    use std::fs;
+
+    /// Unique path under the OS temp dir so parallel test runs don't collide.
+    fn temp_path(name: &str) -> String {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "pcd_py_test_{}_{}_{}",
+            std::process::id(),
+            name,
+            std::ptr::addr_of!(name) as usize
+        ));
+        path.to_string_lossy().into_owned()
+    }
+
+    /// A COUNT>1 field (e.g. normals) must survive a `write_pcd`/`read_pcd`
+    /// round trip with its (N, count) shape and values intact. This is the
+    /// scenario that triggered the `(N,1)` panic fixed in 20106fc.
+    #[test]
+    fn write_pcd_read_pcd_roundtrip_count_gt1() {
+        Python::with_gil(|py| {
+            let path = temp_path("normals.pcd");
+            let data = PyDict::new(py);
+
+            let xs: Vec<f32> = vec![0.0, 1.0, 2.0];
+            let normals: Vec<f32> = vec![0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0];
+            data.set_item("x", xs.clone().into_pyarray(py)).unwrap();
+            data.set_item(
+                "normal",
+                normals.clone().into_pyarray(py).reshape([3, 3]).unwrap(),
+            )
+            .unwrap();
+
+            write_pcd(path.clone(), &data, "binary", None).unwrap();
+            let (meta, result) = read_pcd(path.clone(), None, false).unwrap();
+            let _ = fs::remove_file(&path);
+
+            assert_eq!(meta.points, 3);
+
+            let dict = result.downcast_bound::<PyDict>(py).unwrap();
+            let x_out = dict
+                .get_item("x")
+                .unwrap()
+                .unwrap()
+                .downcast_into::<PyArray1<f32>>()
+                .unwrap()
+                .to_vec()
+                .unwrap();
+            assert_eq!(x_out, xs);
+
+            let normal_out: Vec<f32> = dict
+                .get_item("normal")
+                .unwrap()
+                .unwrap()
+                .downcast_into::<numpy::PyArray2<f32>>()
+                .unwrap()
+                .readonly()
+                .as_array()
+                .iter()
+                .cloned()
+                .collect();
+            assert_eq!(normal_out, normals);
+        });
+    }
+
+    /// A geometry field, a low-cardinality label field, and a COUNT>1 field
+    /// must all survive a `write_parquet`/`read_parquet` round trip, and
+    /// `MetaData.width`/`height`/`viewpoint` must come back as written rather
+    /// than resetting to defaults (the bug fixed in cef76ae).
+    #[test]
+    fn write_parquet_read_parquet_roundtrip() {
+        Python::with_gil(|py| {
+            let path = temp_path("roundtrip.parquet");
+            let data = PyDict::new(py);
+
+            let xs: Vec<f32> = vec![0.0, 1.0, 2.0, 3.0];
+            let labels: Vec<u8> = vec![1, 1, 2, 2];
+            let normals: Vec<f32> =
+                vec![0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+            data.set_item("x", xs.clone().into_pyarray(py)).unwrap();
+            data.set_item("label", labels.clone().into_pyarray(py))
+                .unwrap();
+            data.set_item(
+                "normal",
+                normals.clone().into_pyarray(py).reshape([4, 3]).unwrap(),
+            )
+            .unwrap();
+
+            let viewpoint = vec![1.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0];
+            write_parquet(path.clone(), &data, "zstd", Some(viewpoint.clone())).unwrap();
+            let (meta, dict) = read_parquet(py, path.clone()).unwrap();
+            let _ = fs::remove_file(&path);
+
+            assert_eq!(meta.width, 4);
+            assert_eq!(meta.height, 1);
+            assert_eq!(meta.viewpoint, viewpoint);
+
+            let dict = dict.bind(py);
+            let x_out = dict
+                .get_item("x")
+                .unwrap()
+                .unwrap()
+                .downcast_into::<PyArray1<f32>>()
+                .unwrap()
+                .to_vec()
+                .unwrap();
+            assert_eq!(x_out, xs);
+
+            let label_out = dict
+                .get_item("label")
+                .unwrap()
+                .unwrap()
+                .downcast_into::<PyArray1<u8>>()
+                .unwrap()
+                .to_vec()
+                .unwrap();
+            assert_eq!(label_out, labels);
+
+            let normal_out: Vec<f32> = dict
+                .get_item("normal")
+                .unwrap()
+                .unwrap()
+                .downcast_into::<numpy::PyArray2<f32>>()
+                .unwrap()
+                .readonly()
+                .as_array()
+                .iter()
+                .cloned()
+                .collect();
+            assert_eq!(normal_out, normals);
+        });
+    }
+}